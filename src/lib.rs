@@ -22,6 +22,22 @@ impl Display for Hsl {
     }
 }
 
+impl Hsl {
+    /// Converts this HSL color to RGB using mathematically correct math.
+    ///
+    /// This is the inverse of [`Rgb::to_hsl_accurate`]. The `h`, `s`, and
+    /// `l` fields are interpreted on their native `0-255` scale (`h`
+    /// mapping linearly onto `0-360°`, `s` and `l` onto `0-100%`), but the
+    /// conversion itself uses full-range floating math — it does **not**
+    /// share the deliberate colorgram.py errors baked into [`rgb_to_hsl`].
+    pub fn to_rgb(&self) -> Rgb {
+        let h = self.h as f32 / 255.0 * 360.0;
+        let s = self.s as f32 / 255.0;
+        let l = self.l as f32 / 255.0;
+        hsl_precise_to_rgb(h, s, l)
+    }
+}
+
 /// Represents a color in the RGB (Red, Green, Blue) color space.
 #[derive(PartialEq, Eq, Debug)]
 pub struct Rgb {
@@ -36,6 +52,233 @@ impl Display for Rgb {
     }
 }
 
+impl Rgb {
+    /// Parses a hexadecimal color string into an `Rgb`.
+    ///
+    /// Accepts `#RGB`, `#RRGGBB`, and the same forms without the leading
+    /// `#` (e.g. `RRGGBB`). The short `#RGB` form expands each nibble
+    /// (`#f80` -> `#ff8800`).
+    ///
+    /// ### Arguments:
+    /// * `s` - The hex string to parse.
+    pub fn from_hex(s: &str) -> Result<Rgb, HexError> {
+        let s = s.strip_prefix('#').unwrap_or(s);
+        let bytes = match s.len() {
+            3 => parse_hex_digits(s, true)?,
+            6 => parse_hex_digits(s, false)?,
+            n => return Err(HexError::InvalidLength(n)),
+        };
+        Ok(Rgb {
+            r: bytes[0],
+            g: bytes[1],
+            b: bytes[2],
+        })
+    }
+
+    /// Formats the color as a `#rrggbb` hexadecimal string.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Returns a copy with lightness increased by `amount` (a fraction of
+    /// the full range, e.g. `0.1` for +10%), clamped to valid bounds.
+    pub fn lighten(&self, amount: f32) -> Rgb {
+        let (h, s, l) = rgb_to_hsl_precise(self);
+        hsl_precise_to_rgb(h, s, (l + amount).clamp(0.0, 1.0))
+    }
+
+    /// Returns a copy with lightness decreased by `amount`.
+    pub fn darken(&self, amount: f32) -> Rgb {
+        self.lighten(-amount)
+    }
+
+    /// Returns a copy with saturation increased by `amount`, clamped.
+    pub fn saturate(&self, amount: f32) -> Rgb {
+        let (h, s, l) = rgb_to_hsl_precise(self);
+        hsl_precise_to_rgb(h, (s + amount).clamp(0.0, 1.0), l)
+    }
+
+    /// Returns a copy with saturation decreased by `amount`.
+    pub fn desaturate(&self, amount: f32) -> Rgb {
+        self.saturate(-amount)
+    }
+
+    /// Returns a copy with the hue rotated by `degrees`, wrapping around
+    /// the 0–360° circle.
+    pub fn rotate_hue(&self, degrees: f32) -> Rgb {
+        let (h, s, l) = rgb_to_hsl_precise(self);
+        hsl_precise_to_rgb((h + degrees).rem_euclid(360.0), s, l)
+    }
+
+    /// Converts this color to HSL using mathematically correct math.
+    ///
+    /// Unlike [`rgb_to_hsl`], which reproduces colorgram.py's integer-math
+    /// quirks for bucket compatibility, this computes a standard
+    /// full-range HSL and scales it onto the [`Hsl`] type's native `0-255`
+    /// fields (`h` over `0-360°`, `s` and `l` over `0-100%`). It is the
+    /// inverse of [`Hsl::to_rgb`], so callers can round-trip colors for
+    /// display or manipulation without accumulating error.
+    pub fn to_hsl_accurate(&self) -> Hsl {
+        let (h, s, l) = rgb_to_hsl_precise(self);
+        Hsl {
+            h: (h / 360.0 * 255.0).round() as u8,
+            s: (s * 255.0).round() as u8,
+            l: (l * 255.0).round() as u8,
+        }
+    }
+}
+
+/// Converts RGB to full-precision HSL as `(hue 0–360°, saturation 0–1,
+/// lightness 0–1)`.
+///
+/// Unlike the bug-compatible [`rgb_to_hsl`], this uses standard
+/// floating-point math and is suitable for display and round-tripping
+/// manipulations without accumulating integer-math error.
+fn rgb_to_hsl_precise(rgb: &Rgb) -> (f32, f32, f32) {
+    let r = rgb.r as f32 / 255.0;
+    let g = rgb.g as f32 / 255.0;
+    let b = rgb.b as f32 / 255.0;
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let l = (max + min) / 2.0;
+
+    if chroma == 0.0 {
+        return (0.0, 0.0, l);
+    }
+
+    let s = chroma / (1.0 - (2.0 * l - 1.0).abs());
+
+    let h = if max == r {
+        60.0 * (((g - b) / chroma).rem_euclid(6.0))
+    } else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+
+    (h.rem_euclid(360.0), s, l)
+}
+
+/// Converts full-precision HSL (`hue 0–360°, saturation 0–1, lightness
+/// 0–1`) back to RGB, the exact inverse of [`rgb_to_hsl_precise`].
+fn hsl_precise_to_rgb(h: f32, s: f32, l: f32) -> Rgb {
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h_prime = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+    let m = l - chroma / 2.0;
+
+    let (r, g, b) = match h_prime as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Rgb {
+        r: ((r + m) * 255.0).round() as u8,
+        g: ((g + m) * 255.0).round() as u8,
+        b: ((b + m) * 255.0).round() as u8,
+    }
+}
+
+/// An error produced while parsing a hexadecimal color string.
+#[derive(PartialEq, Eq, Debug)]
+pub enum HexError {
+    /// The string's length (after stripping an optional `#`) is not one of
+    /// the accepted digit counts.
+    InvalidLength(usize),
+    /// The string contains a character that is not a hexadecimal digit.
+    InvalidDigit(char),
+}
+
+impl Display for HexError {
+    fn fmt(&self, f: &mut Formatter) -> fmtResult {
+        match self {
+            HexError::InvalidLength(n) => write!(f, "invalid hex color length: {}", n),
+            HexError::InvalidDigit(c) => write!(f, "invalid hex digit: '{}'", c),
+        }
+    }
+}
+
+impl Error for HexError {}
+
+/// Parses a run of hexadecimal digits into a `Vec` of byte values, expanding
+/// each short (single-digit) nibble to a full byte (`f` -> `0xff`).
+fn parse_hex_digits(s: &str, short: bool) -> Result<Vec<u8>, HexError> {
+    let nibbles: Vec<u8> = s
+        .chars()
+        .map(|c| {
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or(HexError::InvalidDigit(c))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(if short {
+        nibbles.into_iter().map(|n| n << 4 | n).collect()
+    } else {
+        nibbles.chunks(2).map(|p| p[0] << 4 | p[1]).collect()
+    })
+}
+
+/// Represents a color in the RGBA (Red, Green, Blue, Alpha) color space.
+///
+/// Alpha is an 8-bit opacity value where `0` is fully transparent and `255`
+/// is fully opaque.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Display for Rgba {
+    fn fmt(&self, f: &mut Formatter) -> fmtResult {
+        write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, self.a)
+    }
+}
+
+impl Rgba {
+    /// Parses a hexadecimal color string into an `Rgba`.
+    ///
+    /// Accepts `#RGB`, `#RGBA`, `#RRGGBB`, and `#RRGGBBAA`, with or without
+    /// the leading `#`. Forms without an alpha component default to fully
+    /// opaque (`a = 255`).
+    ///
+    /// ### Arguments:
+    /// * `s` - The hex string to parse.
+    pub fn from_hex(s: &str) -> Result<Rgba, HexError> {
+        let s = s.strip_prefix('#').unwrap_or(s);
+        let bytes = match s.len() {
+            3 => parse_hex_digits(s, true)?,
+            4 => parse_hex_digits(s, true)?,
+            6 => parse_hex_digits(s, false)?,
+            8 => parse_hex_digits(s, false)?,
+            n => return Err(HexError::InvalidLength(n)),
+        };
+        Ok(Rgba {
+            r: bytes[0],
+            g: bytes[1],
+            b: bytes[2],
+            a: *bytes.get(3).unwrap_or(&255),
+        })
+    }
+
+    /// Formats the color as a `#rrggbbaa` hexadecimal string.
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+}
+
 /// A structure representing an extracted color, its HSL equivalent,
 /// and its prevalence in the image.
 #[derive(PartialEq, Debug)]
@@ -46,6 +289,9 @@ pub struct Color {
     pub hsl: Hsl,
     /// The proportion of this color in the image (range 0.0 to 1.0).
     pub proportion: f32,
+    /// The averaged alpha of the cluster, if extracted via an alpha-aware
+    /// path. `None` for the opaque extraction functions.
+    pub alpha: Option<u8>,
 }
 
 impl Color {
@@ -60,8 +306,62 @@ impl Color {
             rgb,
             hsl,
             proportion,
+            alpha: None,
+        }
+    }
+
+    /// Creates a new `Color` carrying an averaged alpha value, as produced
+    /// by the alpha-aware extraction path.
+    ///
+    /// ### Arguments:
+    /// * `rgb` - The base RGB color.
+    /// * `proportion` - The weight of this color relative to others.
+    /// * `alpha` - The mean alpha of the contributing pixels.
+    pub fn new_with_alpha(rgb: Rgb, proportion: f32, alpha: u8) -> Color {
+        let hsl = rgb_to_hsl(&rgb);
+        Color {
+            rgb,
+            hsl,
+            proportion,
+            alpha: Some(alpha),
+        }
+    }
+
+    /// Rebuilds this `Color` around a transformed `Rgb`, preserving its
+    /// `proportion` and `alpha` and recomputing the bug-compatible `hsl`.
+    fn with_rgb(&self, rgb: Rgb) -> Color {
+        Color {
+            hsl: rgb_to_hsl(&rgb),
+            rgb,
+            proportion: self.proportion,
+            alpha: self.alpha,
         }
     }
+
+    /// Returns a copy with lightness increased by `amount`. See [`Rgb::lighten`].
+    pub fn lighten(&self, amount: f32) -> Color {
+        self.with_rgb(self.rgb.lighten(amount))
+    }
+
+    /// Returns a copy with lightness decreased by `amount`. See [`Rgb::darken`].
+    pub fn darken(&self, amount: f32) -> Color {
+        self.with_rgb(self.rgb.darken(amount))
+    }
+
+    /// Returns a copy with saturation increased by `amount`. See [`Rgb::saturate`].
+    pub fn saturate(&self, amount: f32) -> Color {
+        self.with_rgb(self.rgb.saturate(amount))
+    }
+
+    /// Returns a copy with saturation decreased by `amount`. See [`Rgb::desaturate`].
+    pub fn desaturate(&self, amount: f32) -> Color {
+        self.with_rgb(self.rgb.desaturate(amount))
+    }
+
+    /// Returns a copy with the hue rotated by `degrees`. See [`Rgb::rotate_hue`].
+    pub fn rotate_hue(&self, degrees: f32) -> Color {
+        self.with_rgb(self.rgb.rotate_hue(degrees))
+    }
 }
 
 /// Converts RGB color space to HSL.
@@ -201,11 +501,427 @@ pub fn extract(buffer: &[u8], number_of_color: usize) -> Result<Vec<Color>, Box<
     Ok(colors)
 }
 
+/// A mutable "box" of pixels used by the median-cut algorithm.
+///
+/// Each box owns a slice of the full pixel list (represented as an index
+/// range into a shared `Vec`) so that splitting only reorders pixels in
+/// place rather than cloning them.
+struct MedianCutBox {
+    start: usize,
+    end: usize,
+}
+
+impl MedianCutBox {
+    /// The number of pixels contained in this box.
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns the channel (0 = R, 1 = G, 2 = B) with the largest extent
+    /// (`max − min`) over this box's pixels, together with that extent.
+    fn widest_channel(&self, pixels: &[[u8; 3]]) -> (usize, u8) {
+        let mut min = [255u8; 3];
+        let mut max = [0u8; 3];
+        for p in &pixels[self.start..self.end] {
+            for c in 0..3 {
+                min[c] = min[c].min(p[c]);
+                max[c] = max[c].max(p[c]);
+            }
+        }
+        let mut channel = 0;
+        let mut extent = 0u8;
+        for c in 0..3 {
+            let e = max[c] - min[c];
+            if e >= extent {
+                extent = e;
+                channel = c;
+            }
+        }
+        (channel, extent)
+    }
+}
+
+/// Extracts a palette of dominant colors using median-cut quantization.
+///
+/// Unlike [`extract`], which collapses pixels into a coarse 6-bit bucket
+/// grid (preserving colorgram.py compatibility), median-cut adapts the
+/// quantization to the actual distribution of colors in the image, giving
+/// perceptually cleaner palettes on gradient-heavy or photographic input.
+///
+/// ### Process:
+/// 1. **Decoding**: Loads the image and converts it to RGB8.
+/// 2. **Seeding**: Collects every pixel into a single box spanning the
+///    min/max of each channel.
+/// 3. **Splitting**: Repeatedly selects the box whose largest channel
+///    extent (`max − min` over R, G, B) is greatest, sorts its pixels
+///    along that channel, and splits it at the median index.
+/// 4. **Termination**: Stops once `number_of_color` boxes exist or no box
+///    can be split further.
+/// 5. **Averaging**: Each box yields a `Color` whose `rgb` is the mean of
+///    its pixels and whose `proportion` is its pixel count over the total.
+///
+/// ### Arguments:
+/// * `buffer` - A byte slice containing encoded image data (e.g., JPEG, PNG).
+/// * `number_of_color` - The maximum number of dominant colors to return.
+pub fn extract_median_cut(
+    buffer: &[u8],
+    number_of_color: usize,
+) -> Result<Vec<Color>, Box<dyn Error>> {
+    let img = image::load_from_memory(buffer)?;
+    let img = img.to_rgb8();
+
+    let mut pixels: Vec<[u8; 3]> = img.pixels().map(|p| [p[0], p[1], p[2]]).collect();
+    let total = pixels.len();
+
+    if total == 0 || number_of_color == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut boxes = vec![MedianCutBox {
+        start: 0,
+        end: total,
+    }];
+
+    while boxes.len() < number_of_color {
+        // Pick the splittable box with the widest channel extent.
+        let mut target: Option<(usize, usize, u8)> = None;
+        for (i, b) in boxes.iter().enumerate() {
+            if b.len() < 2 {
+                continue;
+            }
+            let (channel, extent) = b.widest_channel(&pixels);
+            if extent == 0 {
+                continue;
+            }
+            if target.is_none_or(|(_, _, best)| extent > best) {
+                target = Some((i, channel, extent));
+            }
+        }
+
+        let Some((i, channel, _)) = target else {
+            break;
+        };
+
+        let (start, end) = (boxes[i].start, boxes[i].end);
+        pixels[start..end].sort_unstable_by_key(|p| p[channel]);
+        let mid = start + (end - start) / 2;
+        boxes[i].end = mid;
+        boxes.push(MedianCutBox { start: mid, end });
+    }
+
+    let mut colors = Vec::with_capacity(boxes.len());
+    for b in &boxes {
+        let count = b.len();
+        let mut sum = [0u32; 3];
+        for p in &pixels[b.start..b.end] {
+            for c in 0..3 {
+                sum[c] += p[c] as u32;
+            }
+        }
+        let rgb = Rgb {
+            r: (sum[0] / count as u32) as u8,
+            g: (sum[1] / count as u32) as u8,
+            b: (sum[2] / count as u32) as u8,
+        };
+        colors.push(Color::new(rgb, count as f32 / total as f32));
+    }
+
+    colors.sort_unstable_by(|a, b| b.proportion.partial_cmp(&a.proportion).unwrap());
+
+    Ok(colors)
+}
+
+/// Extracts a palette of dominant colors while respecting transparency.
+///
+/// [`extract`] converts the image with `to_rgb8()`, which silently
+/// composites or drops the alpha channel; fully-transparent pixels then
+/// contribute bogus colors. This variant loads the image with `to_rgba8()`
+/// and:
+///
+/// * **skips** pixels whose alpha is below `alpha_threshold` entirely, so
+///   fully-transparent regions never pollute the palette, and
+/// * **weights** each remaining pixel's bucket contribution by its alpha,
+///   so ghosted (partially-transparent) regions do not dominate.
+///
+/// The returned colors carry an averaged alpha (see [`Color::alpha`]) so
+/// callers building UI themes can distinguish opaque from translucent
+/// dominant colors.
+///
+/// ### Arguments:
+/// * `buffer` - A byte slice containing encoded image data (e.g., PNG, GIF).
+/// * `number_of_color` - The maximum number of dominant colors to return.
+/// * `alpha_threshold` - Pixels with alpha strictly below this value are
+///   ignored.
+pub fn extract_rgba(
+    buffer: &[u8],
+    number_of_color: usize,
+    alpha_threshold: u8,
+) -> Result<Vec<Color>, Box<dyn Error>> {
+    let img = image::load_from_memory(buffer)?;
+    let img = img.to_rgba8();
+
+    // Per bucket: weighted r, g, b sums, weight sum, alpha sum, pixel count.
+    let mut samples = vec![0f64; 6 * 4096];
+
+    for pixel in img.pixels() {
+        let a = pixel[3];
+        if a < alpha_threshold {
+            continue;
+        }
+
+        let rgb = Rgb {
+            r: pixel[0],
+            g: pixel[1],
+            b: pixel[2],
+        };
+        let hsl = rgb_to_hsl(&rgb);
+
+        let y_val = ((rgb.r as f32 * 0.2126 + rgb.g as f32 * 0.7152 + rgb.b as f32 * 0.0722) as u8)
+            & 0b1100_0000;
+        let h = hsl.h & 0b1100_0000;
+        let l = hsl.l & 0b1100_0000;
+
+        let packed = ((y_val as usize) << 4) | ((h as usize) << 2) | (l as usize);
+        let idx = packed * 6;
+
+        let weight = a as f64 / 255.0;
+        samples[idx] += rgb.r as f64 * weight;
+        samples[idx + 1] += rgb.g as f64 * weight;
+        samples[idx + 2] += rgb.b as f64 * weight;
+        samples[idx + 3] += weight;
+        samples[idx + 4] += a as f64;
+        samples[idx + 5] += 1.0;
+    }
+
+    let mut used = Vec::new();
+    for chunk in samples.chunks(6) {
+        let weight = chunk[3];
+        if weight > 0.0 {
+            let avg_rgb = Rgb {
+                r: (chunk[0] / weight).round() as u8,
+                g: (chunk[1] / weight).round() as u8,
+                b: (chunk[2] / weight).round() as u8,
+            };
+            let avg_alpha = (chunk[4] / chunk[5]).round() as u8;
+            used.push((weight, avg_rgb, avg_alpha));
+        }
+    }
+
+    used.sort_unstable_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    let nmin = number_of_color.min(used.len());
+    let sum_weights: f64 = used[..nmin].iter().map(|&(w, _, _)| w).sum();
+
+    let mut colors = Vec::with_capacity(number_of_color);
+    for (weight, rgb, alpha) in used.into_iter().take(number_of_color) {
+        colors.push(Color::new_with_alpha(
+            rgb,
+            (weight / sum_weights) as f32,
+            alpha,
+        ));
+    }
+
+    Ok(colors)
+}
+
+/// Linearizes a single sRGB channel (0.0–1.0) using the sRGB transfer function.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Applies the inverse sRGB transfer function to a linear channel (0.0–1.0).
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an sRGB color to the Oklab color space, returning `[L, a, b]`.
+fn rgb_to_oklab(rgb: &Rgb) -> [f32; 3] {
+    let r = srgb_to_linear(rgb.r as f32 / 255.0);
+    let g = srgb_to_linear(rgb.g as f32 / 255.0);
+    let b = srgb_to_linear(rgb.b as f32 / 255.0);
+
+    let l = 0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+    let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+    let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+    let l_ = l.cbrt();
+    let m_ = m.cbrt();
+    let s_ = s.cbrt();
+
+    [
+        0.210_454_26 * l_ + 0.793_617_8 * m_ - 0.004_072_047 * s_,
+        1.977_998_5 * l_ - 2.428_592_2 * m_ + 0.450_593_7 * s_,
+        0.025_904_037 * l_ + 0.782_771_77 * m_ - 0.808_675_77 * s_,
+    ]
+}
+
+/// Converts an Oklab color (`[L, a, b]`) back to sRGB, clamping to the gamut.
+fn oklab_to_rgb(lab: [f32; 3]) -> Rgb {
+    let l_ = lab[0] + 0.396_337_78 * lab[1] + 0.215_803_76 * lab[2];
+    let m_ = lab[0] - 0.105_561_346 * lab[1] - 0.063_854_17 * lab[2];
+    let s_ = lab[0] - 0.089_484_18 * lab[1] - 1.291_485_5 * lab[2];
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+    Rgb {
+        r: (linear_to_srgb(r).clamp(0.0, 1.0) * 255.0).round() as u8,
+        g: (linear_to_srgb(g).clamp(0.0, 1.0) * 255.0).round() as u8,
+        b: (linear_to_srgb(b).clamp(0.0, 1.0) * 255.0).round() as u8,
+    }
+}
+
+/// Extracts a palette by k-means clustering in the Oklab color space.
+///
+/// Distances in raw RGB do not match human perception, so bucket-based
+/// extraction over-weights bright regions. This backend converts every
+/// pixel to Oklab (where Euclidean distance approximates perceptual
+/// difference), clusters them, and converts the cluster centroids back to
+/// RGB for the returned palette.
+///
+/// ### Process:
+/// 1. **Decoding**: Loads the image and converts it to RGB8.
+/// 2. **Conversion**: Maps every pixel to Oklab `[L, a, b]`.
+/// 3. **Seeding**: Places `number_of_color` centroids by sampling the
+///    pixel set at even intervals.
+/// 4. **Iteration**: Assigns each pixel to the nearest centroid, recomputes
+///    each centroid as the mean of its members, and repeats until
+///    assignments stabilize or a maximum iteration count is reached.
+/// 5. **Averaging**: Each non-empty cluster becomes a `Color` whose `rgb` is
+///    the centroid converted back to sRGB and whose `proportion` is its
+///    cluster membership count over the total pixel count. Clusters left
+///    with no members are dropped.
+///
+/// ### Arguments:
+/// * `buffer` - A byte slice containing encoded image data (e.g., JPEG, PNG).
+/// * `number_of_color` - The number of clusters to seed (capped at the pixel
+///   count); this is the maximum number of colors returned.
+pub fn extract_oklab_kmeans(
+    buffer: &[u8],
+    number_of_color: usize,
+) -> Result<Vec<Color>, Box<dyn Error>> {
+    const MAX_ITERATIONS: usize = 32;
+
+    let img = image::load_from_memory(buffer)?;
+    let img = img.to_rgb8();
+
+    let points: Vec<[f32; 3]> = img
+        .pixels()
+        .map(|p| {
+            rgb_to_oklab(&Rgb {
+                r: p[0],
+                g: p[1],
+                b: p[2],
+            })
+        })
+        .collect();
+    let total = points.len();
+
+    if total == 0 || number_of_color == 0 {
+        return Ok(Vec::new());
+    }
+
+    let k = number_of_color.min(total);
+
+    // Evenly sample the pixel set for initial centroids.
+    let mut centroids: Vec<[f32; 3]> = (0..k).map(|i| points[i * total / k]).collect();
+
+    let mut assignments = vec![0usize; total];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+
+        for (idx, p) in points.iter().enumerate() {
+            let mut best = 0;
+            let mut best_dist = f32::INFINITY;
+            for (c, centroid) in centroids.iter().enumerate() {
+                let d = (p[0] - centroid[0]).powi(2)
+                    + (p[1] - centroid[1]).powi(2)
+                    + (p[2] - centroid[2]).powi(2);
+                if d < best_dist {
+                    best_dist = d;
+                    best = c;
+                }
+            }
+            if assignments[idx] != best {
+                assignments[idx] = best;
+                changed = true;
+            }
+        }
+
+        let mut sums = vec![[0f32; 3]; k];
+        let mut counts = vec![0u32; k];
+        for (idx, p) in points.iter().enumerate() {
+            let c = assignments[idx];
+            sums[c][0] += p[0];
+            sums[c][1] += p[1];
+            sums[c][2] += p[2];
+            counts[c] += 1;
+        }
+        for c in 0..k {
+            if counts[c] > 0 {
+                let n = counts[c] as f32;
+                centroids[c] = [sums[c][0] / n, sums[c][1] / n, sums[c][2] / n];
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut counts = vec![0u32; k];
+    for &c in &assignments {
+        counts[c] += 1;
+    }
+
+    let mut colors = Vec::with_capacity(k);
+    for c in 0..k {
+        if counts[c] == 0 {
+            continue;
+        }
+        let rgb = oklab_to_rgb(centroids[c]);
+        colors.push(Color::new(rgb, counts[c] as f32 / total as f32));
+    }
+
+    colors.sort_unstable_by(|a, b| b.proportion.partial_cmp(&a.proportion).unwrap());
+
+    Ok(colors)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs;
 
+    /// Shared fixture of representative colors (black, white, and a sampled
+    /// mid-tone) used by the conversion round-trip tests.
+    const SAMPLE_RGBS: [Rgb; 3] = [
+        Rgb { r: 0, g: 0, b: 0 },
+        Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        },
+        Rgb {
+            r: 214,
+            g: 163,
+            b: 101,
+        },
+    ];
+
     #[test]
     fn test_rgb_to_hsl() {
         let rgb = Rgb { r: 0, g: 0, b: 0 };
@@ -254,7 +970,8 @@ mod tests {
                     s: 147,
                     l: 157
                 },
-                proportion: 1.0
+                proportion: 1.0,
+                alpha: None
             }]
         );
 
@@ -263,4 +980,177 @@ mod tests {
 
         assert_eq!(amount_of_colors, 35);
     }
+
+    #[test]
+    fn test_extract_median_cut() {
+        let buf = fs::read("test.png").unwrap();
+
+        let colors = extract_median_cut(&buf, 5).unwrap();
+        assert!(colors.len() <= 5);
+
+        // Proportions partition the image, so they must sum to ~1.0.
+        let total: f32 = colors.iter().map(|c| c.proportion).sum();
+        assert!((total - 1.0).abs() < 1e-3);
+
+        // Returned colors are ordered from most to least prevalent.
+        for pair in colors.windows(2) {
+            assert!(pair[0].proportion >= pair[1].proportion);
+        }
+    }
+
+    #[test]
+    fn test_oklab_roundtrip() {
+        // Converting to Oklab and back should recover the original color
+        // within rounding tolerance.
+        for rgb in &SAMPLE_RGBS {
+            let back = oklab_to_rgb(rgb_to_oklab(rgb));
+            assert!((back.r as i32 - rgb.r as i32).abs() <= 1);
+            assert!((back.g as i32 - rgb.g as i32).abs() <= 1);
+            assert!((back.b as i32 - rgb.b as i32).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_extract_oklab_kmeans() {
+        let buf = fs::read("test.png").unwrap();
+
+        let colors = extract_oklab_kmeans(&buf, 5).unwrap();
+        assert!(colors.len() <= 5);
+
+        let total: f32 = colors.iter().map(|c| c.proportion).sum();
+        assert!((total - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rgb_hex() {
+        assert_eq!(
+            Rgb::from_hex("#ff8800").unwrap(),
+            Rgb {
+                r: 255,
+                g: 136,
+                b: 0
+            }
+        );
+        assert_eq!(Rgb::from_hex("#f80").unwrap(), Rgb::from_hex("ff8800").unwrap());
+        assert_eq!(
+            Rgb {
+                r: 214,
+                g: 163,
+                b: 101
+            }
+            .to_hex(),
+            "#d6a365"
+        );
+        assert_eq!(Rgb::from_hex("#ff88").unwrap_err(), HexError::InvalidLength(4));
+        assert!(matches!(
+            Rgb::from_hex("#gg0000").unwrap_err(),
+            HexError::InvalidDigit('g')
+        ));
+    }
+
+    #[test]
+    fn test_rgba_hex() {
+        assert_eq!(
+            Rgba::from_hex("#ff880080").unwrap(),
+            Rgba {
+                r: 255,
+                g: 136,
+                b: 0,
+                a: 128
+            }
+        );
+        // Forms without alpha default to fully opaque.
+        assert_eq!(
+            Rgba::from_hex("#ff8800").unwrap(),
+            Rgba {
+                r: 255,
+                g: 136,
+                b: 0,
+                a: 255
+            }
+        );
+        assert_eq!(Rgba::from_hex("#f808").unwrap().a, 136);
+        assert_eq!(
+            Rgba {
+                r: 255,
+                g: 136,
+                b: 0,
+                a: 128
+            }
+            .to_hex(),
+            "#ff880080"
+        );
+    }
+
+    #[test]
+    fn test_manipulation_roundtrip() {
+        // The precise HSL roundtrip must recover the original color exactly.
+        // A saturated mid-tone is added to the shared fixture to exercise a
+        // non-grey hue.
+        for rgb in SAMPLE_RGBS
+            .iter()
+            .chain(std::iter::once(&Rgb {
+                r: 10,
+                g: 200,
+                b: 120,
+            }))
+        {
+            let (h, s, l) = rgb_to_hsl_precise(rgb);
+            assert_eq!(hsl_precise_to_rgb(h, s, l), *rgb);
+        }
+    }
+
+    #[test]
+    fn test_hsl_accurate_roundtrip() {
+        // The accurate pair is a near-exact roundtrip (within u8 scaling),
+        // unlike the bug-compatible `rgb_to_hsl`.
+        for rgb in &SAMPLE_RGBS {
+            let back = rgb.to_hsl_accurate().to_rgb();
+            assert!((back.r as i32 - rgb.r as i32).abs() <= 2);
+            assert!((back.g as i32 - rgb.g as i32).abs() <= 2);
+            assert!((back.b as i32 - rgb.b as i32).abs() <= 2);
+        }
+    }
+
+    #[test]
+    fn test_manipulation_bounds() {
+        let rgb = Rgb {
+            r: 120,
+            g: 80,
+            b: 40,
+        };
+        // Lightening never lowers the lightness.
+        let lighter = rgb.lighten(0.2);
+        let (_, _, l0) = rgb_to_hsl_precise(&rgb);
+        let (_, _, l1) = rgb_to_hsl_precise(&lighter);
+        assert!(l1 >= l0);
+
+        // Full lighten saturates to white; full darken to black.
+        assert_eq!(
+            rgb.lighten(1.0),
+            Rgb {
+                r: 255,
+                g: 255,
+                b: 255
+            }
+        );
+        assert_eq!(rgb.darken(1.0), Rgb { r: 0, g: 0, b: 0 });
+
+        // Rotating hue by 360° is a no-op.
+        assert_eq!(rgb.rotate_hue(360.0), rgb);
+    }
+
+    #[test]
+    fn test_extract_rgba() {
+        let buf = fs::read("test.png").unwrap();
+
+        // With a zero threshold every pixel contributes and each color
+        // carries an averaged alpha.
+        let colors = extract_rgba(&buf, 5, 0).unwrap();
+        assert!(!colors.is_empty());
+        assert!(colors.iter().all(|c| c.alpha.is_some()));
+
+        let total: f32 = colors.iter().map(|c| c.proportion).sum();
+        assert!((total - 1.0).abs() < 1e-3);
+    }
 }
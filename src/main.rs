@@ -1,8 +1,22 @@
 use ansi_term::{Color::RGB, Style};
-use clap::Parser;
-use colorgram::extract;
+use clap::{Parser, ValueEnum};
+use colorgram::{Color, extract};
+use std::fs;
 use std::path::{PathBuf, absolute};
 
+/// The shape in which the extracted palette is printed to stdout.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// ANSI-painted swatches (the default, human-readable).
+    Ansi,
+    /// A JSON array of `{rgb, hsl, hex, proportion}` objects.
+    Json,
+    /// One `#rrggbb` value per line.
+    Hex,
+    /// CSS custom properties (`--color-N: #rrggbb;`).
+    Css,
+}
+
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
@@ -16,6 +30,68 @@ struct Args {
         help = "Amount of colors to extract"
     )]
     colors_amount: usize,
+
+    #[arg(
+        short = 'f',
+        long = "format",
+        value_enum,
+        default_value_t = Format::Ansi,
+        help = "Output format"
+    )]
+    format: Format,
+}
+
+/// Prints `colors` as ANSI-painted swatches.
+fn print_ansi(colors: &[Color]) {
+    for color in colors {
+        let style = Style::new()
+            .bold()
+            .fg(RGB(255 - color.rgb.r, 255 - color.rgb.g, 255 - color.rgb.b))
+            .on(RGB(color.rgb.r, color.rgb.g, color.rgb.b));
+        let proportion_string = format!("{:.2}%", color.proportion * 100.0);
+        let final_string = format!("{:6} | {}", proportion_string, color.rgb);
+        let output = style.paint(format!("{:1}{:28}", "", final_string));
+        println!("{}", output);
+    }
+}
+
+/// Prints `colors` as a JSON array of `{rgb, hsl, hex, proportion}` objects.
+fn print_json(colors: &[Color]) {
+    let items: Vec<String> = colors
+        .iter()
+        .map(|c| {
+            format!(
+                "{{\"rgb\":{{\"r\":{},\"g\":{},\"b\":{}}},\
+                 \"hsl\":{{\"h\":{},\"s\":{},\"l\":{}}},\
+                 \"hex\":\"{}\",\"proportion\":{}}}",
+                c.rgb.r,
+                c.rgb.g,
+                c.rgb.b,
+                c.hsl.h,
+                c.hsl.s,
+                c.hsl.l,
+                c.rgb.to_hex(),
+                c.proportion
+            )
+        })
+        .collect();
+    println!("[{}]", items.join(","));
+}
+
+/// Prints one `#rrggbb` value per line.
+fn print_hex(colors: &[Color]) {
+    for color in colors {
+        println!("{}", color.rgb.to_hex());
+    }
+}
+
+/// Prints the palette as CSS custom properties.
+fn print_css(colors: &[Color]) {
+    println!(":root {{");
+    for (i, color) in colors.iter().enumerate() {
+        println!("  --color-{}: {};", i, color.rgb.to_hex());
+    }
+    println!("}}");
 }
 
 fn main() {
@@ -28,19 +104,21 @@ fn main() {
     assert!(input_path.is_file(), "Input path is not a file");
     assert!(colors_amount > 0, "Colors amount must be greater than zero");
 
-    match extract(input_path, colors_amount) {
-        Ok(colors) => {
-            for color in colors {
-                let style = Style::new()
-                    .bold()
-                    .fg(RGB(255 - color.rgb.r, 255 - color.rgb.g, 255 - color.rgb.b))
-                    .on(RGB(color.rgb.r, color.rgb.g, color.rgb.b));
-                let proportion_string = format!("{:.2}%", color.proportion * 100.0);
-                let final_string = format!("{:6} | {}", proportion_string, color.rgb);
-                let output = style.paint(format!("{:1}{:28}", "", final_string));
-                println!("{}", output);
-            }
+    let buffer = match fs::read(&input_path) {
+        Ok(buffer) => buffer,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return;
         }
+    };
+
+    match extract(&buffer, colors_amount) {
+        Ok(colors) => match args.format {
+            Format::Ansi => print_ansi(&colors),
+            Format::Json => print_json(&colors),
+            Format::Hex => print_hex(&colors),
+            Format::Css => print_css(&colors),
+        },
         Err(e) => eprintln!("Error: {}", e),
     }
 }